@@ -5,6 +5,56 @@ pub struct Location {
     pub longitude: f64,
 }
 
+/// The base-32 alphabet used by geohashes, in encoding order. Note that this omits the letters
+/// `a`, `i`, `l`, and `o` to avoid confusion with `1`, `0`, and each other.
+const GEOHASH_ALPHABET: &str = "0123456789bcdefghjkmnpqrstuvwxyz";
+
+/// Errors that can occur when decoding a geohash string.
+#[derive(Debug, Clone, PartialEq)]
+pub enum GeohashError {
+    /// The hash was empty.
+    Empty,
+    /// The hash contained a character outside the geohash base-32 alphabet.
+    InvalidCharacter(char),
+}
+
+impl std::fmt::Display for GeohashError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GeohashError::Empty => write!(f, "geohash must not be empty"),
+            GeohashError::InvalidCharacter(c) => {
+                write!(f, "'{c}' is not a valid geohash character")
+            }
+        }
+    }
+}
+
+impl std::error::Error for GeohashError {}
+
+/// Errors returned when constructing a `Location` from out-of-range coordinates.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LocationError {
+    /// The latitude was outside the valid range of [-90, 90] degrees.
+    InvalidLatitude(f64),
+    /// The longitude was outside the valid range of [-180, 180] degrees.
+    InvalidLongitude(f64),
+}
+
+impl std::fmt::Display for LocationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LocationError::InvalidLatitude(lat) => {
+                write!(f, "latitude {lat} is outside the valid range of -90..=90")
+            }
+            LocationError::InvalidLongitude(lon) => {
+                write!(f, "longitude {lon} is outside the valid range of -180..=180")
+            }
+        }
+    }
+}
+
+impl std::error::Error for LocationError {}
+
 impl From<(f64, f64)> for Location {
     fn from(latlon: (f64, f64)) -> Self {
         Location {
@@ -29,6 +79,34 @@ impl Location {
     const MILES: f64 = 3958.76131603933;
     const NAUTICAL_MILES: f64 = Self::MILES * 1.1508;
 
+    /// WGS-84 equatorial radius, in kilometers.
+    const WGS84_EQUATORIAL_KM: f64 = 6378.137;
+    /// WGS-84 polar radius, in kilometers.
+    const WGS84_POLAR_KM: f64 = 6356.752;
+
+    /// Constructs a `Location`, validating that `latitude` is within [-90, 90] and `longitude`
+    /// is within [-180, 180]. Prefer this over the infallible `From` impls when the coordinates
+    /// come from an untrusted source (e.g. parsing a CSV), to avoid silently propagating garbage
+    /// coordinates into distance math.
+    ///
+    /// Note: there is no `TryFrom<(f64, f64)>` impl here, since Rust's coherence rules forbid a
+    /// custom `TryFrom<T>` alongside an existing `From<T>` for the same `T` (the blanket
+    /// `impl<T, U> TryFrom<U> for T where U: Into<T>` would conflict) — use `Location::new`
+    /// directly instead.
+    pub fn new(latitude: f64, longitude: f64) -> Result<Location, LocationError> {
+        if !(-90.0..=90.0).contains(&latitude) {
+            return Err(LocationError::InvalidLatitude(latitude));
+        }
+        if !(-180.0..=180.0).contains(&longitude) {
+            return Err(LocationError::InvalidLongitude(longitude));
+        }
+
+        Ok(Location {
+            latitude,
+            longitude,
+        })
+    }
+
     /// Calculates the distance in miles between two points.
     pub fn distance_mi(&self, other: Location) -> f64 {
         Self::MILES * self.distance(other)
@@ -44,6 +122,271 @@ impl Location {
         Self::KILOMETERS * self.distance(other)
     }
 
+    /// Calculates the distance in kilometers between two points, using the geocentric Earth
+    /// radius at the midpoint latitude (WGS-84 ellipsoid) instead of a fixed mean radius. This
+    /// is more accurate than `distance_km` for pairs far from the equator, at the cost of a
+    /// few extra trig calls.
+    pub fn distance_km_geocentric(&self, other: Location) -> f64 {
+        self.geocentric_radius_km(other) * self.distance(other)
+    }
+
+    /// Calculates the distance in miles between two points, using the geocentric Earth radius
+    /// at the midpoint latitude. See `distance_km_geocentric`.
+    pub fn distance_mi_geocentric(&self, other: Location) -> f64 {
+        let km_to_mi = Self::MILES / Self::KILOMETERS;
+        km_to_mi * self.distance_km_geocentric(other)
+    }
+
+    /// Calculates the distance in nautical miles between two points, using the geocentric
+    /// Earth radius at the midpoint latitude. See `distance_km_geocentric`.
+    pub fn distance_nautical_mi_geocentric(&self, other: Location) -> f64 {
+        let km_to_nmi = Self::NAUTICAL_MILES / Self::KILOMETERS;
+        km_to_nmi * self.distance_km_geocentric(other)
+    }
+
+    /// Computes the WGS-84 geocentric Earth radius, in kilometers, at the midpoint latitude of
+    /// `self` and `other`: R(φ) = sqrt( ((a²·cos φ)² + (b²·sin φ)²) / ((a·cos φ)² + (b·sin φ)²) ).
+    fn geocentric_radius_km(&self, other: Location) -> f64 {
+        let phi: f64 = ((self.latitude + other.latitude) / 2.0).to_radians();
+        let a = Self::WGS84_EQUATORIAL_KM;
+        let b = Self::WGS84_POLAR_KM;
+
+        let a_cos_phi = a * phi.cos();
+        let b_sin_phi = b * phi.sin();
+
+        (((a * a_cos_phi).powi(2) + (b * b_sin_phi).powi(2))
+            / (a_cos_phi.powi(2) + b_sin_phi.powi(2)))
+        .sqrt()
+    }
+
+    /// Calculates the distance in meters between two points using Vincenty's iterative inverse
+    /// solution on the WGS-84 ellipsoid. This is more accurate than the spherical haversine
+    /// distance (which can be off by up to ~0.5%), but returns `None` if the iteration fails to
+    /// converge within 200 iterations, which can happen for near-antipodal points.
+    pub fn distance_vincenty_m(&self, other: Location) -> Option<f64> {
+        const A: f64 = 6378137.0;
+        const F: f64 = 1.0 / 298.257223563;
+        const B: f64 = (1.0 - F) * A;
+
+        if self.latitude == other.latitude && self.longitude == other.longitude {
+            return Some(0.0);
+        }
+
+        let u1: f64 = ((1.0 - F) * self.latitude.to_radians().tan()).atan();
+        let u2: f64 = ((1.0 - F) * other.latitude.to_radians().tan()).atan();
+        let l: f64 = (other.longitude - self.longitude).to_radians();
+
+        let (sin_u1, cos_u1) = u1.sin_cos();
+        let (sin_u2, cos_u2) = u2.sin_cos();
+
+        let mut lambda: f64 = l;
+        let mut sin_sigma;
+        let mut cos_sigma;
+        let mut sigma;
+        let mut sin_alpha;
+        let mut cos_sq_alpha;
+        let mut cos2_sigma_m;
+
+        let mut converged = false;
+        let mut iterations = 0;
+        loop {
+            let (sin_lambda, cos_lambda) = lambda.sin_cos();
+
+            sin_sigma = (((cos_u2 * sin_lambda).powi(2))
+                + (cos_u1 * sin_u2 - sin_u1 * cos_u2 * cos_lambda).powi(2))
+            .sqrt();
+
+            if sin_sigma == 0.0 {
+                return Some(0.0);
+            }
+
+            cos_sigma = sin_u1 * sin_u2 + cos_u1 * cos_u2 * cos_lambda;
+            sigma = sin_sigma.atan2(cos_sigma);
+
+            sin_alpha = cos_u1 * cos_u2 * sin_lambda / sin_sigma;
+            cos_sq_alpha = 1.0 - sin_alpha * sin_alpha;
+
+            cos2_sigma_m = if cos_sq_alpha == 0.0 {
+                0.0
+            } else {
+                cos_sigma - 2.0 * sin_u1 * sin_u2 / cos_sq_alpha
+            };
+
+            let c = F / 16.0 * cos_sq_alpha * (4.0 + F * (4.0 - 3.0 * cos_sq_alpha));
+            let lambda_prev = lambda;
+            lambda = l
+                + (1.0 - c)
+                    * F
+                    * sin_alpha
+                    * (sigma
+                        + c * sin_sigma
+                            * (cos2_sigma_m + c * cos_sigma * (-1.0 + 2.0 * cos2_sigma_m.powi(2))));
+
+            iterations += 1;
+            if (lambda - lambda_prev).abs() < 1e-12 {
+                converged = true;
+                break;
+            }
+            if iterations >= 200 {
+                break;
+            }
+        }
+
+        if !converged {
+            return None;
+        }
+
+        let u_sq = cos_sq_alpha * (A * A - B * B) / (B * B);
+        let big_a =
+            1.0 + u_sq / 16384.0 * (4096.0 + u_sq * (-768.0 + u_sq * (320.0 - 175.0 * u_sq)));
+        let big_b = u_sq / 1024.0 * (256.0 + u_sq * (-128.0 + u_sq * (74.0 - 47.0 * u_sq)));
+        let delta_sigma = big_b
+            * sin_sigma
+            * (cos2_sigma_m
+                + big_b / 4.0
+                    * (cos_sigma * (-1.0 + 2.0 * cos2_sigma_m.powi(2))
+                        - big_b / 6.0
+                            * cos2_sigma_m
+                            * (-3.0 + 4.0 * sin_sigma.powi(2))
+                            * (-3.0 + 4.0 * cos2_sigma_m.powi(2))));
+
+        Some(B * big_a * (sigma - delta_sigma))
+    }
+
+    /// Calculates the initial compass bearing (forward azimuth), in degrees clockwise from
+    /// true north (0–360), along the great circle from `self` to `other`.
+    pub fn bearing(&self, other: Location) -> f64 {
+        let lat1: f64 = self.latitude.to_radians();
+        let lat2: f64 = other.latitude.to_radians();
+        let d_lon: f64 = (other.longitude - self.longitude).to_radians();
+
+        let y: f64 = d_lon.sin() * lat2.cos();
+        let x: f64 = lat1.cos() * lat2.sin() - lat1.sin() * lat2.cos() * d_lon.cos();
+        let theta: f64 = y.atan2(x);
+
+        (theta.to_degrees() + 360.0) % 360.0
+    }
+
+    /// Solves the direct geodesic problem on a sphere: given an initial `bearing_deg` (degrees
+    /// clockwise from true north) and a `distance_km` to travel along the great circle, returns
+    /// the resulting `Location`. This is the inverse of `bearing`/`distance_km` and is useful
+    /// for generating offset points, bounding circles, or simulated tracks.
+    pub fn destination(&self, bearing_deg: f64, distance_km: f64) -> Location {
+        let delta: f64 = distance_km / Self::KILOMETERS;
+        let theta: f64 = bearing_deg.to_radians();
+
+        let lat1: f64 = self.latitude.to_radians();
+        let lon1: f64 = self.longitude.to_radians();
+
+        let lat2: f64 =
+            (lat1.sin() * delta.cos() + lat1.cos() * delta.sin() * theta.cos()).asin();
+        let lon2: f64 = lon1
+            + (theta.sin() * delta.sin() * lat1.cos()).atan2(delta.cos() - lat1.sin() * lat2.sin());
+
+        Location {
+            latitude: lat2.to_degrees(),
+            longitude: (lon2.to_degrees() + 540.0) % 360.0 - 180.0,
+        }
+    }
+
+    /// Solves the direct geodesic problem on a sphere using `distance_mi` in place of
+    /// kilometers. See `destination`.
+    pub fn destination_mi(&self, bearing_deg: f64, distance_mi: f64) -> Location {
+        let distance_km = distance_mi / Self::MILES * Self::KILOMETERS;
+        self.destination(bearing_deg, distance_km)
+    }
+
+    /// Encodes this location as a geohash string of the given `precision` (number of base-32
+    /// characters). Higher precision yields a smaller, more specific cell; a precision of 11 or
+    /// more gives sub-meter resolution.
+    pub fn to_geohash(&self, precision: usize) -> String {
+        let mut lat_range = (-90.0, 90.0);
+        let mut lon_range = (-180.0, 180.0);
+
+        let mut hash = String::with_capacity(precision);
+        let mut bits = 0u8;
+        let mut bit_count = 0;
+        let mut is_lon = true;
+
+        let alphabet: Vec<char> = GEOHASH_ALPHABET.chars().collect();
+
+        while hash.len() < precision {
+            if is_lon {
+                let mid = (lon_range.0 + lon_range.1) / 2.0;
+                if self.longitude >= mid {
+                    bits = (bits << 1) | 1;
+                    lon_range.0 = mid;
+                } else {
+                    bits <<= 1;
+                    lon_range.1 = mid;
+                }
+            } else {
+                let mid = (lat_range.0 + lat_range.1) / 2.0;
+                if self.latitude >= mid {
+                    bits = (bits << 1) | 1;
+                    lat_range.0 = mid;
+                } else {
+                    bits <<= 1;
+                    lat_range.1 = mid;
+                }
+            }
+            is_lon = !is_lon;
+
+            bit_count += 1;
+            if bit_count == 5 {
+                hash.push(alphabet[bits as usize]);
+                bits = 0;
+                bit_count = 0;
+            }
+        }
+
+        hash
+    }
+
+    /// Decodes a geohash string into the `Location` at the center of its cell. Returns a
+    /// `GeohashError` if `hash` is empty or contains a character outside the geohash alphabet.
+    pub fn from_geohash(hash: &str) -> Result<Location, GeohashError> {
+        if hash.is_empty() {
+            return Err(GeohashError::Empty);
+        }
+
+        let mut lat_range = (-90.0, 90.0);
+        let mut lon_range = (-180.0, 180.0);
+        let mut is_lon = true;
+
+        for c in hash.chars() {
+            let idx = GEOHASH_ALPHABET
+                .find(c)
+                .ok_or(GeohashError::InvalidCharacter(c))?;
+
+            for shift in (0..5).rev() {
+                let bit = (idx >> shift) & 1;
+
+                if is_lon {
+                    let mid = (lon_range.0 + lon_range.1) / 2.0;
+                    if bit == 1 {
+                        lon_range.0 = mid;
+                    } else {
+                        lon_range.1 = mid;
+                    }
+                } else {
+                    let mid = (lat_range.0 + lat_range.1) / 2.0;
+                    if bit == 1 {
+                        lat_range.0 = mid;
+                    } else {
+                        lat_range.1 = mid;
+                    }
+                }
+                is_lon = !is_lon;
+            }
+        }
+
+        Ok(Location {
+            latitude: (lat_range.0 + lat_range.1) / 2.0,
+            longitude: (lon_range.0 + lon_range.1) / 2.0,
+        })
+    }
+
     /// Performs the haversine calculation without multiplying by the unit length.
     fn distance(&self, other: Location) -> f64 {
         let d_lat: f64 = (other.latitude - self.latitude).to_radians();
@@ -97,4 +440,167 @@ mod tests {
         let end: Location = (38.897147, -77.043934).into();
         assert_eq!(0.549156547264883, start.distance_km(end));
     }
+
+    #[test]
+    fn new_accepts_boundary_coordinates() {
+        assert!(Location::new(90.0, 180.0).is_ok());
+        assert!(Location::new(-90.0, -180.0).is_ok());
+    }
+
+    #[test]
+    fn new_rejects_out_of_range_latitude() {
+        assert_eq!(
+            Err(LocationError::InvalidLatitude(90.1)),
+            Location::new(90.1, 0.0)
+        );
+        assert_eq!(
+            Err(LocationError::InvalidLatitude(-90.1)),
+            Location::new(-90.1, 0.0)
+        );
+    }
+
+    #[test]
+    fn new_rejects_out_of_range_longitude() {
+        assert_eq!(
+            Err(LocationError::InvalidLongitude(180.1)),
+            Location::new(0.0, 180.1)
+        );
+        assert_eq!(
+            Err(LocationError::InvalidLongitude(-180.1)),
+            Location::new(0.0, -180.1)
+        );
+    }
+
+    #[test]
+    fn destination_due_north_lands_at_expected_distance() {
+        let start: Location = (38.898556, -77.037852).into();
+        let end = start.destination(0.0, 100.0);
+        assert!((start.distance_km(end) - 100.0).abs() < 1e-6);
+        assert!((start.bearing(end) - 0.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn destination_arbitrary_bearing_lands_at_expected_distance() {
+        let start: Location = (38.898556, -77.037852).into();
+        let end = start.destination(47.0, 250.0);
+        assert!((start.distance_km(end) - 250.0).abs() < 1e-6);
+        assert!((start.bearing(end) - 47.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn destination_mi_matches_km_equivalent() {
+        let start: Location = (38.898556, -77.037852).into();
+        let via_mi = start.destination_mi(90.0, 10.0);
+        let via_km = start.destination(90.0, 10.0 / Location::MILES * Location::KILOMETERS);
+        assert_eq!(via_mi, via_km);
+    }
+
+    #[test]
+    fn geohash_round_trip_low_precision() {
+        let start: Location = (38.898556, -77.037852).into();
+        let hash = start.to_geohash(5);
+        let decoded = Location::from_geohash(&hash).unwrap();
+        assert!(start.distance_km(decoded) < 5.0);
+    }
+
+    #[test]
+    fn geohash_round_trip_high_precision() {
+        let start: Location = (38.898556, -77.037852).into();
+        let hash = start.to_geohash(10);
+        let decoded = Location::from_geohash(&hash).unwrap();
+        assert!(start.distance_km(decoded) < 0.001);
+    }
+
+    #[test]
+    fn geohash_known_value() {
+        // Near Jutland, Denmark; the standard example used in geohash documentation.
+        let loc: Location = (57.64911, 10.40744).into();
+        let hash = loc.to_geohash(11);
+        assert_eq!("u4pruydqqvj", hash);
+    }
+
+    #[test]
+    fn geohash_decode_rejects_empty() {
+        assert_eq!(Err(GeohashError::Empty), Location::from_geohash(""));
+    }
+
+    #[test]
+    fn geohash_decode_rejects_invalid_character() {
+        assert_eq!(
+            Err(GeohashError::InvalidCharacter('a')),
+            Location::from_geohash("abc")
+        );
+    }
+
+    #[test]
+    fn vincenty_coincident_points_are_zero() {
+        let start: Location = (38.898556, -77.037852).into();
+        assert_eq!(Some(0.0), start.distance_vincenty_m(start));
+    }
+
+    #[test]
+    fn vincenty_matches_known_distance() {
+        // Two points in downtown Washington, D.C., roughly 550m apart.
+        let start: Location = (38.898556, -77.037852).into();
+        let end: Location = (38.897147, -77.043934).into();
+        let meters = start.distance_vincenty_m(end).unwrap();
+        assert!((meters - 550.3).abs() < 1.0);
+    }
+
+    #[test]
+    fn vincenty_near_antipodal_fails_to_converge() {
+        let start: Location = (0.5, 0.0).into();
+        let end: Location = (-0.5, 179.5).into();
+        assert_eq!(None, start.distance_vincenty_m(end));
+    }
+
+    #[test]
+    fn geocentric_equatorial_distance_matches_fixed_radius() {
+        // Near the equator the geocentric radius is close to the mean radius, so the two
+        // distance calculations should nearly agree.
+        let start: Location = (0.0, 0.0).into();
+        let end: Location = (0.0, 10.0).into();
+        let fixed = start.distance_km(end);
+        let geocentric = start.distance_km_geocentric(end);
+        assert!((fixed - geocentric).abs() < 2.0);
+    }
+
+    #[test]
+    fn geocentric_polar_distance_diverges_from_fixed_radius() {
+        // Near the poles the geocentric radius is noticeably smaller than the mean radius, so
+        // the geocentric distance should be measurably shorter than the fixed-radius one.
+        let start: Location = (85.0, 0.0).into();
+        let end: Location = (85.0, 10.0).into();
+        let fixed = start.distance_km(end);
+        let geocentric = start.distance_km_geocentric(end);
+        assert!(geocentric < fixed);
+    }
+
+    #[test]
+    fn bearing_due_north() {
+        let start: Location = (0.0, 0.0).into();
+        let end: Location = (10.0, 0.0).into();
+        assert!((start.bearing(end) - 0.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn bearing_due_east() {
+        let start: Location = (0.0, 0.0).into();
+        let end: Location = (0.0, 10.0).into();
+        assert!((start.bearing(end) - 90.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn bearing_due_south() {
+        let start: Location = (10.0, 0.0).into();
+        let end: Location = (0.0, 0.0).into();
+        assert!((start.bearing(end) - 180.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn bearing_due_west() {
+        let start: Location = (0.0, 10.0).into();
+        let end: Location = (0.0, 0.0).into();
+        assert!((start.bearing(end) - 270.0).abs() < 1e-9);
+    }
 }